@@ -27,11 +27,50 @@
 //! - [Perl](http://search.cpan.org/~bbkr/Integer-Tiny-0.3/lib/Integer/Tiny.pm)
 
 use std::collections::HashMap;
+use std::ops::BitOr;
+
+/// Named classes of characters that can be combined with `|` and fed into
+/// [`SquishyID::from_classes`] to assemble a key without hand-typing it.
+///
+/// # Examples
+/// ```
+/// use squishyid::CharacterSet;
+///
+/// let classes = CharacterSet::Letters | CharacterSet::Numbers;
+/// assert!(classes.contains(CharacterSet::Numbers));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CharacterSet(u8);
+
+#[allow(non_upper_case_globals)]
+impl CharacterSet {
+    pub const Uppercase: CharacterSet = CharacterSet(0b0001);
+    pub const Lowercase: CharacterSet = CharacterSet(0b0010);
+    pub const Numbers: CharacterSet = CharacterSet(0b0100);
+    pub const Symbols: CharacterSet = CharacterSet(0b1000);
+    pub const Letters: CharacterSet = CharacterSet(Self::Uppercase.0 | Self::Lowercase.0);
+    pub const All: CharacterSet =
+        CharacterSet(Self::Letters.0 | Self::Numbers.0 | Self::Symbols.0);
+
+    /// Returns `true` if `self` includes every class set in `other`.
+    pub fn contains(&self, other: CharacterSet) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl BitOr for CharacterSet {
+    type Output = CharacterSet;
+
+    fn bitor(self, rhs: CharacterSet) -> CharacterSet {
+        CharacterSet(self.0 | rhs.0)
+    }
+}
 
 pub struct SquishyID {
     length: usize,
     characters_to_positions: HashMap<char, usize>,
     positions_to_characters: Vec<char>,
+    min_length: usize,
 }
 
 impl SquishyID {
@@ -54,7 +93,7 @@ impl SquishyID {
     /// - `Key must contain at least 2 characters.`
     /// - `Key must contain unique characters.`
 
-    pub fn new(key: &str) -> Result<Self, &str> {
+    pub fn new(key: &str) -> Result<Self, &'static str> {
         let positions_to_characters: Vec<char> = key.chars().collect();
 
         let length: usize = positions_to_characters.len();
@@ -76,9 +115,100 @@ impl SquishyID {
             length,
             characters_to_positions,
             positions_to_characters,
+            min_length: 0,
         })
     }
 
+    /// Constructs new instance using a key assembled from the given character
+    /// classes, e.g. `SquishyID::from_classes(CharacterSet::Letters | CharacterSet::Numbers)`
+    /// for the SMS-friendly `a-zA-Z0-9` alphabet, without risking a hand-typed
+    /// duplicate or omission.
+    ///
+    /// # Errors
+    /// - `Key must contain at least 2 characters.` - when no classes are given.
+    /// - `Key must contain unique characters.`
+
+    pub fn from_classes(classes: CharacterSet) -> Result<Self, &'static str> {
+        let mut key: String = String::new();
+
+        if classes.contains(CharacterSet::Uppercase) {
+            key.push_str("ABCDEFGHIJKLMNOPQRSTUVWXYZ");
+        }
+        if classes.contains(CharacterSet::Lowercase) {
+            key.push_str("abcdefghijklmnopqrstuvwxyz");
+        }
+        if classes.contains(CharacterSet::Numbers) {
+            key.push_str("0123456789");
+        }
+        if classes.contains(CharacterSet::Symbols) {
+            key.push_str("!\"#$%&'()*+,-./:;<=>?@[\\]^_`{|}~");
+        }
+
+        Self::new(&key)
+    }
+
+    /// Constructs new instance using given key, with its alphabet consistently
+    /// shuffled by a salt.
+    ///
+    /// Two instances built from the same key but different salts produce unrelated
+    /// encodings, which makes recovering the key from encoded/decoded samples much
+    /// harder than with [`Self::new`]. An empty salt leaves the key unshuffled.
+    ///
+    /// # Errors
+    /// - `Key must contain at least 2 characters.`
+    /// - `Key must contain unique characters.`
+
+    pub fn with_salt(key: &str, salt: &str) -> Result<Self, &'static str> {
+        let mut instance: Self = Self::new(key)?;
+        instance.shuffle(salt.as_bytes());
+        Ok(instance)
+    }
+
+    /// Consistently permutes `positions_to_characters` (and rebuilds the matching
+    /// reverse lookup) using the Hashids-style shuffle driven by `salt`. A no-op for
+    /// an empty salt.
+    fn shuffle(&mut self, salt: &[u8]) {
+        if salt.is_empty() {
+            return;
+        }
+
+        let mut p: usize = 0;
+        let mut i: usize = self.length - 1;
+
+        while i > 0 {
+            let v: usize = (self.length - 1 - i) % salt.len();
+            let salt_value: usize = salt[v] as usize;
+            p += salt_value;
+            let j: usize = (salt_value + v + p) % i;
+            self.positions_to_characters.swap(i, j);
+            i -= 1;
+        }
+
+        self.characters_to_positions = self
+            .positions_to_characters
+            .iter()
+            .enumerate()
+            .map(|(position, &character)| (character, position))
+            .collect();
+    }
+
+    /// Sets the minimum length of encoded values, padding shorter ones on their
+    /// high-magnitude (leftmost) side so an observer can't tell a small value from
+    /// a large one just by looking at the encoded length.
+    ///
+    /// Padding is made of additional key characters and is fully reversible:
+    /// [`Self::decode`]/[`Self::decode_u128`] discard it automatically. Applies to
+    /// [`Self::encode`]/[`Self::decode`] and [`Self::encode_u128`]/[`Self::decode_u128`]
+    /// (and therefore [`Self::encode_uuid`]/[`Self::decode_uuid`]), and transitively to
+    /// [`Self::encode_checked`]/[`Self::decode_checked`] since those pad the payload before
+    /// computing/checking the check character, but not to
+    /// [`Self::encode_bytes`]/[`Self::decode_bytes`].
+
+    pub fn with_min_length(mut self, min_length: usize) -> Self {
+        self.min_length = min_length;
+        self
+    }
+
     /// Encodes number using characters from the key.
     ///
     /// Note that **this should not be considered a strong encryption**.
@@ -94,7 +224,7 @@ impl SquishyID {
             encoded.push(self.positions_to_characters[position as usize]);
             decoded /= self.length as u64;
 
-            if decoded == 0 {
+            if decoded == 0 && encoded.len() >= self.min_length {
                 break;
             }
         }
@@ -104,6 +234,9 @@ impl SquishyID {
 
     /// Decodes string using characters from the key.
     ///
+    /// A leading run of the key's zero-position character beyond the significant
+    /// digits is treated as padding added by [`Self::with_min_length`] and discarded.
+    ///
     /// # Errors
     /// - `Encoded value must contain at least 1 character.`
     /// - `Encoded value contains character not present in key.`
@@ -114,9 +247,16 @@ impl SquishyID {
             return Err("Encoded value must contain at least 1 character.");
         }
 
+        let characters: Vec<char> = encoded.chars().collect();
+        let zero_character: char = self.positions_to_characters[0];
+        let significant_start: usize = characters
+            .iter()
+            .position(|&character| character != zero_character)
+            .unwrap_or(characters.len() - 1);
+
         let mut decoded: u64 = 0;
 
-        for (position, character) in encoded.chars().rev().enumerate() {
+        for (position, &character) in characters[significant_start..].iter().rev().enumerate() {
             let factor: u64 = match self.characters_to_positions.get(&character) {
                 None => return Err("Encoded value contains character not present in key."),
                 Some(&factor) => factor as u64,
@@ -134,6 +274,281 @@ impl SquishyID {
 
         Ok(decoded)
     }
+
+    /// Encodes a 128-bit number using characters from the key.
+    ///
+    /// Works exactly like [`Self::encode`], but for the wider `u128` range,
+    /// which is large enough to hold a UUID. Also honors [`Self::with_min_length`].
+
+    pub fn encode_u128(&self, mut decoded: u128) -> String {
+        let mut encoded: Vec<char> = Vec::new();
+
+        loop {
+            let position: u128 = decoded % (self.length as u128);
+            encoded.push(self.positions_to_characters[position as usize]);
+            decoded /= self.length as u128;
+
+            if decoded == 0 && encoded.len() >= self.min_length {
+                break;
+            }
+        }
+
+        encoded.iter().rev().collect()
+    }
+
+    /// Decodes string into a 128-bit number using characters from the key.
+    ///
+    /// Works exactly like [`Self::decode`], but for the wider `u128` range,
+    /// which is large enough to hold a UUID. A leading run of the key's
+    /// zero-position character beyond the significant digits is treated as
+    /// padding added by [`Self::with_min_length`] and discarded.
+    ///
+    /// # Errors
+    /// - `Encoded value must contain at least 1 character.`
+    /// - `Encoded value contains character not present in key.`
+    /// - `Encoded value too big to decode.` - when it would cause `u128` overflow.
+
+    pub fn decode_u128(&self, encoded: &str) -> Result<u128, &str> {
+        if encoded.len() == 0 {
+            return Err("Encoded value must contain at least 1 character.");
+        }
+
+        let characters: Vec<char> = encoded.chars().collect();
+        let zero_character: char = self.positions_to_characters[0];
+        let significant_start: usize = characters
+            .iter()
+            .position(|&character| character != zero_character)
+            .unwrap_or(characters.len() - 1);
+
+        let mut decoded: u128 = 0;
+
+        for (position, &character) in characters[significant_start..].iter().rev().enumerate() {
+            let factor: u128 = match self.characters_to_positions.get(&character) {
+                None => return Err("Encoded value contains character not present in key."),
+                Some(&factor) => factor as u128,
+            };
+
+            match (self.length as u128)
+                .checked_pow(position as u32)
+                .and_then(|a| a.checked_mul(factor))
+                .and_then(|a| a.checked_add(decoded))
+            {
+                None => return Err("Encoded value too big to decode."),
+                Some(bigger_decoded) => decoded = bigger_decoded,
+            }
+        }
+
+        Ok(decoded)
+    }
+
+    /// Encodes a UUID, treated as a big-endian `u128`, using characters from the key.
+    ///
+    /// This collapses a 36-character UUID into a handful of key characters,
+    /// just like [`Self::encode`] does for plain integers.
+
+    pub fn encode_uuid(&self, uuid: [u8; 16]) -> String {
+        self.encode_u128(u128::from_be_bytes(uuid))
+    }
+
+    /// Decodes string into a UUID, treated as a big-endian `u128`, using characters from the key.
+    ///
+    /// # Errors
+    /// - `Encoded value must contain at least 1 character.`
+    /// - `Encoded value contains character not present in key.`
+    /// - `Encoded value too big to decode.` - when it would cause `u128` overflow.
+
+    pub fn decode_uuid(&self, encoded: &str) -> Result<[u8; 16], &str> {
+        self.decode_u128(encoded).map(u128::to_be_bytes)
+    }
+
+    /// Encodes number using characters from the key, and appends a trailing check
+    /// character computed with the Luhn mod-N algorithm over the key alphabet.
+    ///
+    /// This lets [`Self::decode_checked`] catch a single mistyped or corrupted
+    /// character, at the cost of one extra character in the result.
+
+    pub fn encode_checked(&self, decoded: u64) -> String {
+        let mut encoded: String = self.encode(decoded);
+
+        // `unwrap` is safe here: every character in `encoded` comes from the key.
+        let sum: usize = self.luhn_checksum(encoded.chars().rev(), 2).unwrap();
+        let check_position: usize = (self.length - sum) % self.length;
+        encoded.push(self.positions_to_characters[check_position]);
+
+        encoded
+    }
+
+    /// Decodes string using characters from the key, validating the trailing check
+    /// character appended by [`Self::encode_checked`].
+    ///
+    /// # Errors
+    /// - `Encoded value must contain at least 1 character.`
+    /// - `Encoded value contains character not present in key.`
+    /// - `Encoded value too big to decode.` - when it would cause `u64` overflow.
+    /// - `Check character mismatch.` - when the trailing check character does not match.
+
+    pub fn decode_checked(&self, encoded: &str) -> Result<u64, &str> {
+        if encoded.len() == 0 {
+            return Err("Encoded value must contain at least 1 character.");
+        }
+
+        if self.luhn_checksum(encoded.chars().rev(), 1)? != 0 {
+            return Err("Check character mismatch.");
+        }
+
+        let mut characters: Vec<char> = encoded.chars().collect();
+        characters.pop();
+        let value: String = characters.into_iter().collect();
+
+        self.decode(&value)
+    }
+
+    /// Computes `sum % self.length` for the Luhn mod-N algorithm, walking `characters`
+    /// right-to-left and alternating `factor` between 2 and 1, starting with the given
+    /// `factor`.
+    fn luhn_checksum(
+        &self,
+        characters: impl Iterator<Item = char>,
+        mut factor: usize,
+    ) -> Result<usize, &str> {
+        let mut sum: usize = 0;
+
+        for character in characters {
+            let position: usize = match self.characters_to_positions.get(&character) {
+                None => return Err("Encoded value contains character not present in key."),
+                Some(&position) => position,
+            };
+
+            let addend: usize = factor * position;
+            factor = if factor == 2 { 1 } else { 2 };
+            let addend: usize = addend / self.length + addend % self.length;
+            sum += addend;
+        }
+
+        Ok(sum % self.length)
+    }
+
+    /// Encodes an arbitrary-length byte string, treated as a big-endian arbitrary-precision
+    /// integer, using characters from the key.
+    ///
+    /// Unlike [`Self::encode`]/[`Self::encode_u128`] this isn't limited to a machine word, so
+    /// it can compactly obfuscate things like SHA-1 fragments or SSH key blobs. The exact byte
+    /// sequence round-trips through [`Self::decode_bytes`], including leading zero bytes and the
+    /// empty slice, by recording the leading zero count in a leading character - which can only
+    /// represent counts up to `self.length - 1`.
+    ///
+    /// # Errors
+    /// - `Too many leading zero bytes to encode losslessly.` - when `bytes` has `self.length`
+    ///   or more zero bytes before its first non-zero byte (for an all-zero slice, before its
+    ///   last byte); use a longer key, or strip/record the leading zeros yourself.
+
+    pub fn encode_bytes(&self, bytes: &[u8]) -> Result<String, &str> {
+        // An empty slice has no leading zero run and no digits at all - it is encoded as
+        // the bare prefix character, which `decode_bytes` recognizes by the absence of any
+        // trailing digit. Without this, `encode_bytes(&[])` would be indistinguishable from
+        // `encode_bytes(&[0])`.
+        if bytes.is_empty() {
+            return Ok(self.positions_to_characters[0].to_string());
+        }
+
+        let leading_zeros: usize = bytes
+            .iter()
+            .position(|&byte| byte != 0)
+            .unwrap_or_else(|| bytes.len() - 1);
+
+        if leading_zeros >= self.length {
+            return Err("Too many leading zero bytes to encode losslessly.");
+        }
+
+        let mut buffer: Vec<u8> = bytes.to_vec();
+        let mut digits: Vec<char> = Vec::new();
+
+        loop {
+            let remainder: usize = self.divmod_buffer(&mut buffer);
+            digits.push(self.positions_to_characters[remainder]);
+
+            if Self::buffer_is_zero(&buffer) {
+                break;
+            }
+        }
+
+        let mut encoded: String = String::new();
+        encoded.push(self.positions_to_characters[leading_zeros]);
+        encoded.extend(digits.iter().rev());
+        Ok(encoded)
+    }
+
+    /// Decodes string into the byte string encoded by [`Self::encode_bytes`].
+    ///
+    /// # Errors
+    /// - `Encoded value must contain at least 1 character.`
+    /// - `Encoded value contains character not present in key.`
+
+    pub fn decode_bytes(&self, encoded: &str) -> Result<Vec<u8>, &str> {
+        if encoded.len() == 0 {
+            return Err("Encoded value must contain at least 1 character.");
+        }
+
+        let mut characters = encoded.chars();
+        let leading_zeros_character: char = characters.next().unwrap();
+
+        let leading_zeros: usize = match self.characters_to_positions.get(&leading_zeros_character) {
+            None => return Err("Encoded value contains character not present in key."),
+            Some(&position) => position,
+        };
+
+        // No digits follow the prefix character: this is the empty slice encoded by
+        // `encode_bytes(&[])`, not a leading zero run followed by a zero value.
+        if characters.clone().next().is_none() {
+            return Ok(Vec::new());
+        }
+
+        let mut buffer: Vec<u8> = Vec::new();
+
+        for character in characters {
+            let position: usize = match self.characters_to_positions.get(&character) {
+                None => return Err("Encoded value contains character not present in key."),
+                Some(&position) => position,
+            };
+
+            let mut carry: usize = position;
+            for byte in buffer.iter_mut().rev() {
+                let value: usize = (*byte as usize) * self.length + carry;
+                *byte = (value % 256) as u8;
+                carry = value / 256;
+            }
+            while carry > 0 {
+                buffer.insert(0, (carry % 256) as u8);
+                carry /= 256;
+            }
+        }
+
+        if buffer.is_empty() {
+            buffer.push(0);
+        }
+
+        let mut decoded: Vec<u8> = vec![0u8; leading_zeros];
+        decoded.extend(buffer);
+        Ok(decoded)
+    }
+
+    /// Divides the big-endian bignum held in `buffer` by `self.length` in place,
+    /// returning the remainder - the next (least significant) digit.
+    fn divmod_buffer(&self, buffer: &mut [u8]) -> usize {
+        let mut remainder: usize = 0;
+
+        for byte in buffer.iter_mut() {
+            let value: usize = remainder * 256 + (*byte as usize);
+            *byte = (value / self.length) as u8;
+            remainder = value % self.length;
+        }
+
+        remainder
+    }
+
+    fn buffer_is_zero(buffer: &[u8]) -> bool {
+        buffer.iter().all(|&byte| byte == 0)
+    }
 }
 
 #[cfg(test)]
@@ -221,4 +636,263 @@ mod tests {
             Err("Encoded value too big to decode.")
         ));
     }
+
+    #[test]
+    fn transcode_u128_value() {
+        let s = SquishyID::new("FujSBZHkPMincNQr6pq0mgxw2tXAsyb8DWV534EC1RUIlYoGOJhed9afKT7vzL")
+            .unwrap();
+        assert_eq!(s.encode_u128(u64::MAX as u128), s.encode(u64::MAX));
+        assert_eq!(s.decode_u128(&s.encode_u128(u128::MAX)).unwrap(), u128::MAX);
+    }
+
+    #[test]
+    fn decode_u128_overflow() {
+        let s = SquishyID::new("0123456789ABCDEF").unwrap();
+        assert!(matches!(
+            s.decode_u128("100000000000000000000000000000000"),
+            Err("Encoded value too big to decode.")
+        ));
+    }
+
+    #[test]
+    fn transcode_uuid() {
+        let s = SquishyID::new("FujSBZHkPMincNQr6pq0mgxw2tXAsyb8DWV534EC1RUIlYoGOJhed9afKT7vzL")
+            .unwrap();
+        let uuid: [u8; 16] = [
+            0xc9, 0xa6, 0x46, 0xd3, 0x9c, 0x61, 0x4c, 0xb7, 0xbf, 0xcd, 0xee, 0x25, 0x22, 0xc8,
+            0xf6, 0x33,
+        ];
+        let encoded: String = s.encode_uuid(uuid);
+        assert_eq!(s.decode_uuid(&encoded).unwrap(), uuid);
+    }
+
+    #[test]
+    fn transcode_checked() {
+        let s = SquishyID::new("FujSBZHkPMincNQr6pq0mgxw2tXAsyb8DWV534EC1RUIlYoGOJhed9afKT7vzL")
+            .unwrap();
+        let encoded: String = s.encode_checked(48888851145);
+        assert_eq!(s.decode_checked(&encoded).unwrap(), 48888851145);
+    }
+
+    #[test]
+    fn encode_checked_with_min_length_pads_transitively() {
+        let s = SquishyID::new("ab").unwrap().with_min_length(5);
+        let encoded: String = s.encode_checked(1);
+
+        // The check character adds one more on top of the padded payload.
+        assert_eq!(encoded.chars().count(), 6);
+        assert_eq!(s.decode_checked(&encoded).unwrap(), 1);
+    }
+
+    #[test]
+    fn decode_checked_detects_typo() {
+        let s = SquishyID::new("FujSBZHkPMincNQr6pq0mgxw2tXAsyb8DWV534EC1RUIlYoGOJhed9afKT7vzL")
+            .unwrap();
+        let mut encoded: String = s.encode_checked(48888851145);
+
+        // Corrupt the first character, leaving the check character untouched.
+        let mut characters: Vec<char> = encoded.chars().collect();
+        characters[0] = if characters[0] == 'F' { 'u' } else { 'F' };
+        encoded = characters.into_iter().collect();
+
+        assert!(matches!(
+            s.decode_checked(&encoded),
+            Err("Check character mismatch.")
+        ));
+    }
+
+    #[test]
+    fn decode_checked_empty_string() {
+        let s = SquishyID::new("ab").unwrap();
+        assert!(matches!(
+            s.decode_checked(""),
+            Err("Encoded value must contain at least 1 character.")
+        ));
+    }
+
+    #[test]
+    fn encode_with_min_length_pads() {
+        let s = SquishyID::new("ab").unwrap().with_min_length(5);
+        let encoded: String = s.encode(0);
+        assert_eq!(encoded, "aaaaa");
+        assert_eq!(s.decode(&encoded).unwrap(), 0);
+
+        let encoded: String = s.encode(1);
+        assert_eq!(encoded.chars().count(), 5);
+        assert_eq!(s.decode(&encoded).unwrap(), 1);
+    }
+
+    #[test]
+    fn encode_with_min_length_shorter_than_value() {
+        let s = SquishyID::new("ab").unwrap().with_min_length(1);
+        let encoded: String = s.encode(48888851145);
+        assert_eq!(s.decode(&encoded).unwrap(), 48888851145);
+    }
+
+    #[test]
+    fn decode_tolerates_hand_written_padding() {
+        let s = SquishyID::new("ab").unwrap();
+        assert_eq!(s.decode("aaab").unwrap(), 1);
+        assert_eq!(s.decode("aaaa").unwrap(), 0);
+    }
+
+    #[test]
+    fn encode_u128_with_min_length_pads() {
+        let s = SquishyID::new("ab").unwrap().with_min_length(5);
+
+        let encoded: String = s.encode_u128(0);
+        assert_eq!(encoded, "aaaaa");
+        assert_eq!(s.decode_u128(&encoded).unwrap(), 0);
+
+        let encoded: String = s.encode_u128(1);
+        assert_eq!(encoded.chars().count(), 5);
+        assert_eq!(s.decode_u128(&encoded).unwrap(), 1);
+    }
+
+    #[test]
+    fn encode_uuid_with_min_length_pads() {
+        let s = SquishyID::new("ab").unwrap().with_min_length(5);
+        let uuid: [u8; 16] = [0; 16];
+        let encoded: String = s.encode_uuid(uuid);
+        assert!(encoded.chars().count() >= 5);
+        assert_eq!(s.decode_uuid(&encoded).unwrap(), uuid);
+    }
+
+    #[test]
+    fn with_salt_validates_key() {
+        assert!(matches!(
+            SquishyID::with_salt("a", "salt"),
+            Err("Key must contain at least 2 characters.")
+        ));
+        assert!(matches!(
+            SquishyID::with_salt("aa", "salt"),
+            Err("Key must contain unique characters.")
+        ));
+    }
+
+    #[test]
+    fn with_salt_transcodes_losslessly() {
+        let s = SquishyID::with_salt(
+            "FujSBZHkPMincNQr6pq0mgxw2tXAsyb8DWV534EC1RUIlYoGOJhed9afKT7vzL",
+            "some salt",
+        )
+        .unwrap();
+        let encoded: String = s.encode(48888851145);
+        assert_eq!(s.decode(&encoded).unwrap(), 48888851145);
+    }
+
+    #[test]
+    fn with_salt_changes_encoding() {
+        let key = "FujSBZHkPMincNQr6pq0mgxw2tXAsyb8DWV534EC1RUIlYoGOJhed9afKT7vzL";
+        let plain = SquishyID::new(key).unwrap();
+        let salted = SquishyID::with_salt(key, "some salt").unwrap();
+        assert_ne!(plain.encode(48888851145), salted.encode(48888851145));
+    }
+
+    #[test]
+    fn with_salt_empty_salt_is_noop() {
+        let key = "FujSBZHkPMincNQr6pq0mgxw2tXAsyb8DWV534EC1RUIlYoGOJhed9afKT7vzL";
+        let plain = SquishyID::new(key).unwrap();
+        let unsalted = SquishyID::with_salt(key, "").unwrap();
+        assert_eq!(plain.encode(48888851145), unsalted.encode(48888851145));
+    }
+
+    #[test]
+    fn character_set_contains() {
+        let classes = CharacterSet::Letters | CharacterSet::Numbers;
+        assert!(classes.contains(CharacterSet::Uppercase));
+        assert!(classes.contains(CharacterSet::Lowercase));
+        assert!(classes.contains(CharacterSet::Numbers));
+        assert!(!classes.contains(CharacterSet::Symbols));
+    }
+
+    #[test]
+    fn from_classes_builds_expected_key() {
+        let s = SquishyID::from_classes(CharacterSet::Letters | CharacterSet::Numbers).unwrap();
+        assert_eq!(
+            s.positions_to_characters.iter().collect::<String>(),
+            "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789"
+        );
+        let encoded: String = s.encode(48888851145);
+        assert_eq!(s.decode(&encoded).unwrap(), 48888851145);
+    }
+
+    #[test]
+    fn from_classes_no_classes() {
+        assert!(matches!(
+            SquishyID::from_classes(CharacterSet(0)),
+            Err("Key must contain at least 2 characters.")
+        ));
+    }
+
+    #[test]
+    fn transcode_bytes() {
+        let s = SquishyID::new("FujSBZHkPMincNQr6pq0mgxw2tXAsyb8DWV534EC1RUIlYoGOJhed9afKT7vzL")
+            .unwrap();
+
+        let bytes: Vec<u8> = vec![0x12, 0x34];
+        let encoded: String = s.encode_bytes(&bytes).unwrap();
+        assert_eq!(s.decode_bytes(&encoded).unwrap(), bytes);
+    }
+
+    #[test]
+    fn transcode_bytes_preserves_leading_zeros() {
+        let s = SquishyID::new("FujSBZHkPMincNQr6pq0mgxw2tXAsyb8DWV534EC1RUIlYoGOJhed9afKT7vzL")
+            .unwrap();
+
+        // A SHA-1 fragment-shaped digest starting with a zero byte.
+        let bytes: Vec<u8> = (0u8..20).collect();
+        let encoded: String = s.encode_bytes(&bytes).unwrap();
+        assert_eq!(s.decode_bytes(&encoded).unwrap(), bytes);
+
+        let bytes: Vec<u8> = vec![0, 0, 0, 1, 2, 3];
+        let encoded: String = s.encode_bytes(&bytes).unwrap();
+        assert_eq!(s.decode_bytes(&encoded).unwrap(), bytes);
+
+        let bytes: Vec<u8> = vec![0, 0, 0];
+        let encoded: String = s.encode_bytes(&bytes).unwrap();
+        assert_eq!(s.decode_bytes(&encoded).unwrap(), bytes);
+    }
+
+    #[test]
+    fn encode_bytes_too_many_leading_zeros() {
+        // Key of length 2 can only represent a leading-zero count of 0 or 1.
+        let s = SquishyID::new("ab").unwrap();
+
+        assert!(s.encode_bytes(&[0, 1]).is_ok());
+        assert!(matches!(
+            s.encode_bytes(&[0, 0, 0, 0, 1]),
+            Err("Too many leading zero bytes to encode losslessly.")
+        ));
+    }
+
+    #[test]
+    fn transcode_bytes_empty_slice() {
+        let s = SquishyID::new("ab").unwrap();
+
+        let empty_encoded: String = s.encode_bytes(&[]).unwrap();
+        let zero_encoded: String = s.encode_bytes(&[0]).unwrap();
+
+        assert_ne!(empty_encoded, zero_encoded);
+        assert_eq!(s.decode_bytes(&empty_encoded).unwrap(), Vec::<u8>::new());
+        assert_eq!(s.decode_bytes(&zero_encoded).unwrap(), vec![0]);
+    }
+
+    #[test]
+    fn decode_bytes_empty_string() {
+        let s = SquishyID::new("ab").unwrap();
+        assert!(matches!(
+            s.decode_bytes(""),
+            Err("Encoded value must contain at least 1 character.")
+        ));
+    }
+
+    #[test]
+    fn decode_bytes_character_not_in_key() {
+        let s = SquishyID::new("ab").unwrap();
+        assert!(matches!(
+            s.decode_bytes("x"),
+            Err("Encoded value contains character not present in key.")
+        ));
+    }
 }